@@ -0,0 +1,31 @@
+//! Config-facing IPC commands: let the frontend read and persist [`AppConfig`].
+
+use std::sync::Mutex;
+
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{generate_handler, Runtime, State};
+
+use crate::config::AppConfig;
+
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+  Builder::new("config")
+    .invoke_handler(generate_handler![get_config, set_config])
+    .build()
+}
+
+#[tauri::command]
+fn get_config(state: State<'_, Mutex<AppConfig>>) -> Result<AppConfig, String> {
+  Ok(state.lock().map_err(|err| err.to_string())?.clone())
+}
+
+/// Persists `config` to disk and makes it the config every subsystem sees
+/// from here on. Subsystems that read the config once at startup (logging,
+/// desktop integration, ...) keep running with what they booted with until
+/// the app restarts; the manual "check for updates" command reads this
+/// state fresh, so its endpoint updates immediately.
+#[tauri::command]
+fn set_config(state: State<'_, Mutex<AppConfig>>, config: AppConfig) -> Result<(), String> {
+  config.save().map_err(|err| err.to_string())?;
+  *state.lock().map_err(|err| err.to_string())? = config;
+  Ok(())
+}
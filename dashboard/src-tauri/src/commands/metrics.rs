@@ -0,0 +1,30 @@
+//! Metrics-facing IPC commands: lightweight process stats for the dashboard's
+//! overview pane.
+
+use serde::Serialize;
+use std::sync::OnceLock;
+use std::time::Instant;
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{generate_handler, Runtime};
+
+static STARTED_AT: OnceLock<Instant> = OnceLock::new();
+
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+  STARTED_AT.get_or_init(Instant::now);
+  Builder::new("metrics")
+    .invoke_handler(generate_handler![get_uptime_seconds])
+    .build()
+}
+
+#[derive(Debug, Serialize)]
+struct Uptime {
+  seconds: u64,
+}
+
+#[tauri::command]
+fn get_uptime_seconds() -> Result<Uptime, String> {
+  let started_at = STARTED_AT.get_or_init(Instant::now);
+  Ok(Uptime {
+    seconds: started_at.elapsed().as_secs(),
+  })
+}
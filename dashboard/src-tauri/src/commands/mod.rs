@@ -0,0 +1,24 @@
+//! IPC command subsystem: one internal Tauri plugin per feature area, each
+//! following `Builder::new("name").invoke_handler(generate_handler![...]).build()`.
+//!
+//! New commands are added by: (1) writing the command fn in the relevant
+//! submodule, (2) adding it to that submodule's `generate_handler!` list,
+//! and (3) nothing else — [`register`] already wires up every plugin here.
+//! This is the single place command plugins are registered; a plugin must
+//! be registered before `.run()` for its commands to resolve. Each plugin
+//! name must also be unique across the app, since a later plugin with the
+//! same name replaces an earlier one.
+
+mod config;
+mod metrics;
+mod updater;
+
+/// Registers every command plugin on the builder. Call this before `.run()`.
+pub fn register<R: tauri::Runtime>(
+  builder: tauri::Builder<R>,
+) -> tauri::Builder<R> {
+  builder
+    .plugin(config::init())
+    .plugin(metrics::init())
+    .plugin(updater::init())
+}
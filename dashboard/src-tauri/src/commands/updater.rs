@@ -0,0 +1,36 @@
+//! Manual "check for updates" command, backed by the same logic the
+//! startup check uses.
+
+use std::sync::Mutex;
+
+use tauri::plugin::{Builder, TauriPlugin};
+use tauri::{generate_handler, AppHandle, Runtime, State};
+
+use crate::config::AppConfig;
+use crate::updater;
+
+pub fn init<R: Runtime>() -> TauriPlugin<R> {
+  // Not "updater": that name belongs to `tauri-plugin-updater`, which is
+  // registered after this plugin and would replace it.
+  Builder::new("app-updater")
+    .invoke_handler(generate_handler![check_for_updates])
+    .build()
+}
+
+/// Unlike the startup check (which uses the endpoint as of boot), this reads
+/// the current config, so a `set_config` edit is reflected immediately.
+#[tauri::command]
+async fn check_for_updates<R: Runtime>(
+  app: AppHandle<R>,
+  state: State<'_, Mutex<AppConfig>>,
+) -> Result<(), String> {
+  let endpoint = state
+    .lock()
+    .map_err(|err| err.to_string())?
+    .updater
+    .endpoint
+    .clone();
+  updater::check_for_updates(&app, &endpoint)
+    .await
+    .map_err(|e| e.to_string())
+}
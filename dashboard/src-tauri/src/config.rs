@@ -0,0 +1,174 @@
+//! App-wide configuration loaded once at the start of `run()` and threaded
+//! into subsystems.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// Top-level configuration for the Coda Dashboard backend.
+///
+/// Falls back to [`AppConfig::default`] for any section missing from the
+/// on-disk config, so operators only need to override what they care about.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AppConfig {
+  pub logging: LoggingConfig,
+  pub desktop: DesktopConfig,
+  pub updater: UpdaterConfig,
+}
+
+impl Default for AppConfig {
+  fn default() -> Self {
+    Self {
+      logging: LoggingConfig::default(),
+      desktop: DesktopConfig::default(),
+      updater: UpdaterConfig::default(),
+    }
+  }
+}
+
+impl AppConfig {
+  /// Loads configuration from the on-disk config file, falling back to
+  /// `AppConfig::default()` (and thus each section's own default) if the
+  /// file is missing or fails to parse.
+  pub fn load() -> Self {
+    let Some(path) = Self::path() else {
+      return Self::default();
+    };
+
+    match fs::read_to_string(&path) {
+      Ok(raw) => Self::parse(&raw).unwrap_or_else(|err| {
+        log::warn!("failed to parse {}, using defaults: {err}", path.display());
+        Self::default()
+      }),
+      Err(_) => Self::default(),
+    }
+  }
+
+  /// Parses a config file's raw contents. Split out from [`Self::load`] so
+  /// the fallback-on-malformed-input behavior is testable without touching
+  /// the filesystem.
+  fn parse(raw: &str) -> serde_json::Result<Self> {
+    serde_json::from_str(raw)
+  }
+
+  /// Writes this config back to the on-disk config file, creating its
+  /// parent directory if needed.
+  pub fn save(&self) -> std::io::Result<()> {
+    let path = Self::path().ok_or_else(|| {
+      std::io::Error::new(std::io::ErrorKind::NotFound, "no config directory available")
+    })?;
+    if let Some(parent) = path.parent() {
+      fs::create_dir_all(parent)?;
+    }
+    let raw = serde_json::to_string_pretty(self)
+      .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+    fs::write(path, raw)
+  }
+
+  /// Path to the config file, e.g. `~/.config/coda-dashboard/config.json`
+  /// on Linux. `None` if the platform has no known config directory.
+  fn path() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join("coda-dashboard").join("config.json"))
+  }
+}
+
+/// Desktop-only integration toggles; ignored on mobile targets.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DesktopConfig {
+  /// Launch Coda Dashboard automatically at login.
+  pub autostart: bool,
+}
+
+impl Default for DesktopConfig {
+  fn default() -> Self {
+    Self { autostart: false }
+  }
+}
+
+/// Self-update endpoint and the public key used to verify staged updates.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UpdaterConfig {
+  /// Release endpoint URL queried for newer versions.
+  pub endpoint: String,
+  /// minisign/ed25519 public key used to verify update signatures.
+  pub pubkey: String,
+}
+
+impl Default for UpdaterConfig {
+  fn default() -> Self {
+    Self {
+      endpoint: "https://releases.coda.app/dashboard/latest.json".into(),
+      pubkey: String::new(),
+    }
+  }
+}
+
+/// Controls verbosity, rotation, and which sinks receive log output.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoggingConfig {
+  /// Minimum level emitted, e.g. `"info"`, `"debug"`, `"trace"`.
+  pub level: String,
+  /// Human-readable rotation threshold, e.g. `"10 MiB"`.
+  pub rotation_size: String,
+  /// Number of rotated files to keep alongside the active log.
+  pub max_rotated_files: u32,
+  pub targets: LogTargets,
+}
+
+impl Default for LoggingConfig {
+  fn default() -> Self {
+    Self {
+      level: "info".into(),
+      rotation_size: "10 MiB".into(),
+      max_rotated_files: 5,
+      targets: LogTargets::default(),
+    }
+  }
+}
+
+/// Which log sinks are active. All three can run simultaneously.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LogTargets {
+  pub webview: bool,
+  pub stdout: bool,
+  pub file: bool,
+}
+
+impl Default for LogTargets {
+  fn default() -> Self {
+    Self {
+      webview: true,
+      stdout: true,
+      file: true,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_rejects_malformed_json() {
+    assert!(AppConfig::parse("not json").is_err());
+  }
+
+  #[test]
+  fn parse_accepts_a_round_tripped_default() {
+    let raw = serde_json::to_string(&AppConfig::default()).unwrap();
+    let parsed = AppConfig::parse(&raw).unwrap();
+    assert_eq!(parsed.updater.endpoint, AppConfig::default().updater.endpoint);
+  }
+
+  #[test]
+  fn parse_fills_in_missing_sections_with_defaults() {
+    let parsed = AppConfig::parse("{}").unwrap();
+    assert_eq!(parsed.logging.level, LoggingConfig::default().level);
+  }
+}
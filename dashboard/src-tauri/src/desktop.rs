@@ -0,0 +1,90 @@
+//! Desktop integration: single-instance focusing, a system tray that keeps
+//! the app alive when the main window closes, and autostart-at-login.
+//!
+//! Gated to desktop targets only; mobile has no equivalent of any of this.
+
+use tauri::menu::{Menu, MenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{App, Manager, Wry};
+use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
+
+use crate::config::DesktopConfig;
+
+/// Wires up the tray and applies the autostart toggle from the `setup`
+/// closure. Single-instance is registered separately, directly on the
+/// builder, since it must be installed before `.run()`.
+pub fn setup(app: &App<Wry>, config: &DesktopConfig) -> tauri::Result<()> {
+  setup_tray(app)?;
+  setup_autostart(app, config)?;
+  Ok(())
+}
+
+fn setup_tray(app: &App<Wry>) -> tauri::Result<()> {
+  let show = MenuItem::with_id(app, "show", "Show", true, None::<&str>)?;
+  let hide = MenuItem::with_id(app, "hide", "Hide", true, None::<&str>)?;
+  let quit = MenuItem::with_id(app, "quit", "Quit", true, None::<&str>)?;
+  let menu = Menu::with_items(app, &[&show, &hide, &quit])?;
+
+  TrayIconBuilder::new()
+    .menu(&menu)
+    .show_menu_on_left_click(true)
+    .on_menu_event(|app, event| match event.id.as_ref() {
+      "show" => {
+        if let Some(window) = app.get_webview_window("main") {
+          let _ = window.show();
+          let _ = window.set_focus();
+        }
+      }
+      "hide" => {
+        if let Some(window) = app.get_webview_window("main") {
+          let _ = window.hide();
+        }
+      }
+      "quit" => app.exit(0),
+      _ => {}
+    })
+    .build(app)?;
+
+  // Keep the app running in the tray when the window is closed: swallow the
+  // close request and hide instead, so "quit" (from the tray menu) is the
+  // only thing that actually exits.
+  if let Some(window) = app.get_webview_window("main") {
+    let window_handle = window.clone();
+    window.on_window_event(move |event| {
+      if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+        api.prevent_close();
+        let _ = window_handle.hide();
+      }
+    });
+  }
+
+  Ok(())
+}
+
+fn setup_autostart(app: &App<Wry>, config: &DesktopConfig) -> tauri::Result<()> {
+  app.handle().plugin(tauri_plugin_autostart::init(
+    MacosLauncher::LaunchAgent,
+    None,
+  ))?;
+
+  let autostart = app.autolaunch();
+  if config.autostart {
+    let _ = autostart.enable();
+  } else {
+    let _ = autostart.disable();
+  }
+
+  Ok(())
+}
+
+/// Installs `tauri-plugin-single-instance`, focusing the existing window's
+/// webview when a second launch is attempted instead of spawning a
+/// duplicate. Must be registered on the builder before `.run()`.
+pub fn single_instance_plugin<R: tauri::Runtime>() -> tauri::plugin::TauriPlugin<R> {
+  tauri_plugin_single_instance::init(|app, _args, _cwd| {
+    if let Some(window) = app.get_webview_window("main") {
+      let _ = window.show();
+      let _ = window.set_focus();
+    }
+  })
+}
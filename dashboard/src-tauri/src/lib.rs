@@ -1,24 +1,73 @@
+mod commands;
+mod config;
+#[cfg(desktop)]
+mod desktop;
+mod logging;
+#[cfg(mobile)]
+mod mobile;
+mod runtime_mode;
+mod updater;
+
+use std::sync::Mutex;
+
+use config::AppConfig;
+use runtime_mode::RuntimeMode;
+use tauri::Manager;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-  tauri::Builder::default()
-    .setup(|app| {
-      // Configure logging
-      if cfg!(debug_assertions) {
-        app.handle().plugin(
-          tauri_plugin_log::Builder::default()
-            .level(log::LevelFilter::Info)
-            .build(),
-        )?;
-      }
+  let app_config = AppConfig::load();
+  let mode = RuntimeMode::current();
+
+  let mut builder = tauri::Builder::default();
+
+  // `tauri-plugin-single-instance` must be the first plugin registered on
+  // the builder, or it can't guarantee it sees (and blocks) a second
+  // launch before anything else does.
+  #[cfg(desktop)]
+  {
+    builder = builder.plugin(desktop::single_instance_plugin());
+  }
+
+  let builder = commands::register(builder)
+    .plugin(tauri_plugin_dialog::init())
+    .plugin(updater::plugin(&app_config.updater));
+
+  builder
+    .manage(Mutex::new(app_config.clone()))
+    .setup(move |app| {
+      // Logging is always installed, dev and production, so deployed
+      // dashboards keep a log trail without a rebuild.
+      app
+        .handle()
+        .plugin(logging::build_plugin(&app_config.logging, mode))?;
+
+      #[cfg(mobile)]
+      mobile::setup(app)?;
+
+      #[cfg(desktop)]
+      desktop::setup(app, &app_config.desktop)?;
 
       // Log startup information
-      log::info!("Coda Dashboard starting up");
+      log::info!("Coda Dashboard starting up ({mode:?})");
       log::info!("Version: {}", env!("CARGO_PKG_VERSION"));
 
+      #[cfg(desktop)]
+      if mode.is_dev() {
+        if let Some(window) = app.get_webview_window("main") {
+          window.open_devtools();
+        }
+      }
+
+      // Only check for updates in production; dev builds track source, not
+      // the release endpoint. The version just logged above is the
+      // baseline the updater compares the release endpoint against.
+      if mode.is_production() {
+        updater::check_on_startup(app.handle().clone(), app_config.updater.endpoint.clone());
+      }
+
       Ok(())
     })
-    // Add custom commands here if needed
-    // .invoke_handler(tauri::generate_handler![command1, command2])
     .run(tauri::generate_context!())
     .expect("error while running tauri application");
 }
@@ -0,0 +1,101 @@
+//! Logging subsystem: builds the `tauri-plugin-log` plugin from [`LoggingConfig`],
+//! wiring up webview, stdout, and a size-rotated log file target.
+
+use byte_unit::Byte;
+use tauri_plugin_log::{fern, Target, TargetKind};
+use time::macros::format_description;
+
+use crate::config::LoggingConfig;
+use crate::runtime_mode::RuntimeMode;
+
+/// Builds the log plugin for the given config. Always installed, in both
+/// dev and production builds, so release dashboards keep a log trail. Dev
+/// mode overrides the configured level to `Debug` so local runs are verbose
+/// regardless of what's checked in for production.
+pub fn build_plugin<R: tauri::Runtime>(
+  config: &LoggingConfig,
+  mode: RuntimeMode,
+) -> tauri_plugin_log::TauriPlugin<R> {
+  let level = if mode.is_dev() {
+    log::LevelFilter::Debug
+  } else {
+    parse_level(&config.level)
+  };
+
+  let mut builder = tauri_plugin_log::Builder::default()
+    .level(level)
+    .max_file_size(rotation_bytes(&config.rotation_size))
+    .rotation_strategy(tauri_plugin_log::RotationStrategy::KeepSome(
+      config.max_rotated_files as usize,
+    ))
+    .timezone_strategy(tauri_plugin_log::TimezoneStrategy::UseLocal)
+    .format(|out, message, record| {
+      out.finish(format_args!(
+        "{} [{}] {}",
+        tauri_plugin_log::TimezoneStrategy::UseLocal
+          .get_now()
+          .format(format_description!(
+            "[year]-[month]-[day] [hour]:[minute]:[second]"
+          ))
+          .unwrap_or_default(),
+        record.level(),
+        message
+      ))
+    });
+
+  let mut targets = Vec::new();
+  if config.targets.webview {
+    targets.push(Target::new(TargetKind::Webview));
+  }
+  if config.targets.stdout {
+    targets.push(Target::new(TargetKind::Stdout));
+  }
+  if config.targets.file {
+    targets.push(Target::new(TargetKind::LogDir { file_name: None }));
+  }
+  builder = builder.targets(targets);
+
+  #[cfg(feature = "color-logs")]
+  {
+    builder = builder.with_colors(fern::colors::ColoredLevelConfig::default());
+  }
+
+  builder.build()
+}
+
+fn parse_level(level: &str) -> log::LevelFilter {
+  level.parse().unwrap_or(log::LevelFilter::Info)
+}
+
+/// Parses a human-readable size like `"10 MiB"` into a byte count, falling
+/// back to 10 MiB if the config value is malformed.
+fn rotation_bytes(human: &str) -> u128 {
+  Byte::parse_str(human, true)
+    .map(|b| b.as_u128())
+    .unwrap_or(10 * 1024 * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_level_accepts_known_levels() {
+    assert_eq!(parse_level("debug"), log::LevelFilter::Debug);
+  }
+
+  #[test]
+  fn parse_level_falls_back_to_info_on_garbage() {
+    assert_eq!(parse_level("not a level"), log::LevelFilter::Info);
+  }
+
+  #[test]
+  fn rotation_bytes_parses_human_sizes() {
+    assert_eq!(rotation_bytes("10 MiB"), 10 * 1024 * 1024);
+  }
+
+  #[test]
+  fn rotation_bytes_falls_back_to_10mib_on_garbage() {
+    assert_eq!(rotation_bytes("not a size"), 10 * 1024 * 1024);
+  }
+}
@@ -0,0 +1,87 @@
+//! Mobile-specific setup, compiled only for Android/iOS targets.
+//!
+//! Keeps mobile-only work out of `run()`: its `setup` closure calls
+//! [`setup`] here on mobile (and `desktop::setup` on desktop), with this
+//! module handling the pieces that only make sense on a phone (native logger
+//! backend, runtime permissions).
+
+use tauri::{App, Manager, Wry};
+
+/// Performs Android/iOS-specific setup so the rest of `run()` can stay
+/// identical across platforms. Called from the `setup` closure before
+/// plugins that expect permissions (camera, notifications, ...) to already
+/// be in flight.
+pub fn setup(app: &App<Wry>) -> tauri::Result<()> {
+  init_platform_logger();
+  request_runtime_permissions(app);
+  Ok(())
+}
+
+#[cfg(target_os = "android")]
+fn init_platform_logger() {
+  android_logger::init_once(
+    android_logger::Config::default().with_max_level(log::LevelFilter::Info),
+  );
+}
+
+#[cfg(target_os = "ios")]
+fn init_platform_logger() {
+  oslog::OsLogger::new("com.coda.dashboard")
+    .level_filter(log::LevelFilter::Info)
+    .init()
+    .ok();
+}
+
+/// Requests the runtime permissions the dashboard needs on mobile. Desktop
+/// has no equivalent concept, which is exactly why this lives here rather
+/// than in the shared `setup` closure.
+fn request_runtime_permissions(app: &App<Wry>) {
+  let _ = app.app_handle();
+  request_platform_permissions();
+}
+
+/// Android 13+ requires `POST_NOTIFICATIONS` to be granted at runtime
+/// before the dashboard can surface update/status notifications.
+#[cfg(target_os = "android")]
+fn request_platform_permissions() {
+  use jni::objects::JValue;
+
+  let ctx = ndk_context::android_context();
+  let vm = match unsafe { jni::JavaVM::from_raw(ctx.vm().cast()) } {
+    Ok(vm) => vm,
+    Err(err) => {
+      log::warn!("failed to attach to JVM for permission request: {err}");
+      return;
+    }
+  };
+  let mut env = match vm.attach_current_thread() {
+    Ok(env) => env,
+    Err(err) => {
+      log::warn!("failed to attach JNI thread for permission request: {err}");
+      return;
+    }
+  };
+  let activity = unsafe { jni::objects::JObject::from_raw(ctx.context().cast()) };
+
+  let result = (|| -> jni::errors::Result<()> {
+    let permission = env.new_string("android.permission.POST_NOTIFICATIONS")?;
+    let permissions = env.new_object_array(1, "java/lang/String", &permission)?;
+    env.call_method(
+      &activity,
+      "requestPermissions",
+      "([Ljava/lang/String;I)V",
+      &[JValue::Object(&permissions.into()), JValue::Int(0)],
+    )?;
+    Ok(())
+  })();
+
+  if let Err(err) = result {
+    log::warn!("failed to request runtime permissions: {err}");
+  }
+}
+
+/// iOS has no equivalent of Android's runtime permission prompts for the
+/// permissions the dashboard currently needs; add a platform call here if a
+/// future plugin (camera, notifications, ...) requires one.
+#[cfg(not(target_os = "android"))]
+fn request_platform_permissions() {}
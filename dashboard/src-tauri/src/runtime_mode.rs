@@ -0,0 +1,30 @@
+//! Single source of truth for dev vs. production, based on the `dev` cfg
+//! Tauri sets instead of the deprecated `custom-protocol` feature.
+
+/// Runtime mode the app was built/launched in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeMode {
+  Dev,
+  Production,
+}
+
+impl RuntimeMode {
+  pub fn current() -> Self {
+    #[cfg(dev)]
+    {
+      RuntimeMode::Dev
+    }
+    #[cfg(not(dev))]
+    {
+      RuntimeMode::Production
+    }
+  }
+
+  pub fn is_dev(self) -> bool {
+    matches!(self, RuntimeMode::Dev)
+  }
+
+  pub fn is_production(self) -> bool {
+    matches!(self, RuntimeMode::Production)
+  }
+}
@@ -0,0 +1,125 @@
+//! Self-update subsystem built on `tauri-plugin-updater`. Checks the
+//! configured release endpoint against `env!("CARGO_PKG_VERSION")`, staging
+//! and signature-verifying any newer build before prompting to restart.
+
+use tauri::{AppHandle, Manager, Runtime};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use tauri_plugin_updater::UpdaterExt;
+use url::Url;
+
+use crate::config::UpdaterConfig;
+
+/// Installs the updater plugin with the minisign/ed25519 public key used to
+/// verify staged updates.
+///
+/// The release endpoint isn't set here: the plugin's own `Builder` has no
+/// `endpoints()` method (that lives on `UpdaterBuilder`, handed out per
+/// check by [`tauri_plugin_updater::UpdaterExt::updater_builder`]), and
+/// `config.endpoint` is hand-editable on disk or over IPC anyway, so it's
+/// resolved fresh in [`check_for_updates`] instead of being baked in here.
+pub fn plugin<R: Runtime>(config: &UpdaterConfig) -> tauri::plugin::TauriPlugin<R> {
+  tauri_plugin_updater::Builder::new()
+    .pubkey(config.pubkey.clone())
+    .build()
+}
+
+/// Checks for an update in the background and, if one is available, stages
+/// it (with signature verification handled by the plugin) and prompts the
+/// user to restart. Startup continues regardless of the outcome.
+pub fn check_on_startup<R: Runtime>(app: AppHandle<R>, endpoint: String) {
+  tauri::async_runtime::spawn(async move {
+    if let Err(err) = check_for_updates(&app, &endpoint).await {
+      log::warn!("update check failed: {err}");
+    }
+  });
+}
+
+/// The logic behind the manual "check for updates" command as well as the
+/// startup check. `endpoint` is resolved by the caller (rather than read
+/// from the plugin's own config) so a `set_config` edit takes effect on the
+/// very next check without a restart.
+pub async fn check_for_updates<R: Runtime>(
+  app: &AppHandle<R>,
+  endpoint: &str,
+) -> tauri::Result<()> {
+  let updater = app
+    .updater_builder()
+    .endpoints(vec![resolve_endpoint(endpoint)])
+    .map_err(|e| tauri::Error::Anyhow(anyhow::anyhow!("invalid updater endpoint: {e}")))?
+    .build()
+    .map_err(|e| tauri::Error::Anyhow(anyhow::anyhow!("failed to build updater: {e}")))?;
+
+  let Some(update) = updater
+    .check()
+    .await
+    .map_err(|e| tauri::Error::Anyhow(anyhow::anyhow!("update check failed: {e}")))?
+  else {
+    log::info!(
+      "no update available, running {}",
+      env!("CARGO_PKG_VERSION")
+    );
+    return Ok(());
+  };
+
+  log::info!(
+    "update {} available (current {})",
+    update.version,
+    env!("CARGO_PKG_VERSION")
+  );
+
+  update
+    .download_and_install(|_chunk, _total| {}, || {})
+    .await
+    .map_err(|e| tauri::Error::Anyhow(anyhow::anyhow!("update install failed: {e}")))?;
+
+  let version = update.version.clone();
+  let app = app.clone();
+  tauri::async_runtime::spawn_blocking(move || {
+    let restart_now = app
+      .dialog()
+      .message(format!(
+        "Coda Dashboard {version} has been downloaded. Restart now to finish updating?"
+      ))
+      .title("Update ready")
+      .kind(MessageDialogKind::Info)
+      .buttons(MessageDialogButtons::OkCancel)
+      .blocking_show();
+
+    if restart_now {
+      app.restart();
+    } else {
+      log::info!("update {version} staged; restart deferred by the user");
+    }
+  });
+
+  Ok(())
+}
+
+/// Parses `endpoint` as a URL, falling back to the default release endpoint
+/// (with a warning) if it's malformed.
+fn resolve_endpoint(endpoint: &str) -> Url {
+  endpoint.parse().unwrap_or_else(|err| {
+    log::warn!("invalid updater endpoint {endpoint:?} ({err}), using the default");
+    UpdaterConfig::default()
+      .endpoint
+      .parse()
+      .expect("default updater endpoint must be a valid URL")
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn resolve_endpoint_parses_a_valid_url() {
+    let url = resolve_endpoint("https://example.com/update.json");
+    assert_eq!(url.as_str(), "https://example.com/update.json");
+  }
+
+  #[test]
+  fn resolve_endpoint_falls_back_to_the_default_on_malformed_input() {
+    let url = resolve_endpoint("not a url");
+    assert_eq!(url.as_str(), UpdaterConfig::default().endpoint);
+  }
+}